@@ -0,0 +1,71 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array, StringArray};
+use arrow::util::bench_util::create_string_array_with_len;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_expr::{ColumnarValue, ScalarUDFImpl};
+use datafusion_functions::unicode::rpad::RPadFunc;
+
+/// Builds the three `rpad(col, length, fill)` arguments for `size` rows,
+/// either as the scalar-literal `length`/`fill` this fast path targets, or as
+/// arrays of the same values to exercise the pre-existing general path.
+fn rpad_args(size: usize, str_len: usize, scalar_length_and_fill: bool) -> Vec<ColumnarValue> {
+    let strings: ArrayRef = Arc::new(create_string_array_with_len::<i32>(size, 0.0, str_len));
+    let length = str_len as i64 + 10;
+    let fill = " ";
+
+    if scalar_length_and_fill {
+        vec![
+            ColumnarValue::Array(strings),
+            ColumnarValue::Scalar(length.into()),
+            ColumnarValue::Scalar(fill.into()),
+        ]
+    } else {
+        let lengths: ArrayRef = Arc::new(Int64Array::from(vec![length; size]));
+        let fills: ArrayRef = Arc::new(StringArray::from(vec![fill; size]));
+        vec![
+            ColumnarValue::Array(strings),
+            ColumnarValue::Array(lengths),
+            ColumnarValue::Array(fills),
+        ]
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let rpad = RPadFunc::new();
+    for size in [1024, 8192] {
+        for str_len in [8, 64] {
+            let scalar_args = rpad_args(size, str_len, true);
+            c.bench_function(
+                &format!("rpad scalar fast path: size {size}, str_len {str_len}"),
+                |b| b.iter(|| black_box(rpad.invoke(&scalar_args).unwrap())),
+            );
+
+            let array_args = rpad_args(size, str_len, false);
+            c.bench_function(
+                &format!("rpad array path: size {size}, str_len {str_len}"),
+                |b| b.iter(|| black_box(rpad.invoke(&array_args).unwrap())),
+            );
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
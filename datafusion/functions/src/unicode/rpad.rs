@@ -24,55 +24,733 @@ use datafusion_common::cast::{
     as_generic_string_array, as_int64_array, as_string_view_array,
 };
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::utils::{make_scalar_function, utf8_to_str_type};
-use datafusion_common::{exec_err, Result};
+use datafusion_common::{exec_err, Result, ScalarValue};
 use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
+/// The `(Utf8View | Utf8 | LargeUtf8, Int64, Utf8View | Utf8 | LargeUtf8)`
+/// signature shared by `rpad` and its sibling padding variants (each of them
+/// also accepts the two-argument form via the `Exact` overloads with no third
+/// argument, padding with spaces).
+fn rpad_like_signature() -> Signature {
+    use DataType::*;
+    Signature::one_of(
+        vec![
+            Exact(vec![Utf8View, Int64]),
+            Exact(vec![Utf8View, Int64, Utf8View]),
+            Exact(vec![Utf8View, Int64, Utf8]),
+            Exact(vec![Utf8View, Int64, LargeUtf8]),
+            Exact(vec![Utf8, Int64]),
+            Exact(vec![Utf8, Int64, Utf8View]),
+            Exact(vec![Utf8, Int64, Utf8]),
+            Exact(vec![Utf8, Int64, LargeUtf8]),
+            Exact(vec![LargeUtf8, Int64]),
+            Exact(vec![LargeUtf8, Int64, Utf8View]),
+            Exact(vec![LargeUtf8, Int64, Utf8]),
+            Exact(vec![LargeUtf8, Int64, LargeUtf8]),
+        ],
+        Volatility::Immutable,
+    )
+}
+
+#[derive(Debug)]
+pub struct RPadFunc {
+    signature: Signature,
+}
+
+impl Default for RPadFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RPadFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: rpad_like_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RPadFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "rpad"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "rpad")
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if let Some(result) = rpad_scalar_fast_path(args)? {
+            return Ok(result);
+        }
+        match args.len() {
+            2 => match args[0].data_type() {
+                DataType::Utf8 | DataType::Utf8View => {
+                    make_scalar_function(rpad::<i32, i32>, vec![])(args)
+                }
+                DataType::LargeUtf8 => {
+                    make_scalar_function(rpad::<i64, i64>, vec![])(args)
+                }
+                other => exec_err!("Unsupported data type {other:?} for function rpad"),
+            },
+            3 => match (args[0].data_type(), args[2].data_type()) {
+                (
+                    DataType::Utf8 | DataType::Utf8View,
+                    DataType::Utf8 | DataType::Utf8View,
+                ) => make_scalar_function(rpad::<i32, i32>, vec![])(args),
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad::<i64, i64>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::Utf8View | DataType::Utf8) => {
+                    make_scalar_function(rpad::<i64, i32>, vec![])(args)
+                }
+                (DataType::Utf8View | DataType::Utf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad::<i32, i64>, vec![])(args)
+                }
+                (first_type, last_type) => {
+                    exec_err!("unsupported arguments type for rpad, first argument type is {}, last argument type is {}", first_type, last_type)
+                }
+            },
+            number => {
+                exec_err!("unsupported arguments number {} for rpad", number)
+            }
+        }
+    }
+}
+
+/// Selects whether padding/truncation functions in the `unicode` module count
+/// string length in grapheme clusters (matching how a terminal displays most
+/// text) or in Unicode code points (matching PostgreSQL, which is unaware of
+/// grapheme clustering). This is the shared toggle that [`rpad`] and
+/// [`rpad_codepoints`] build on; sibling padding/substring functions in this
+/// module should route their own length counting through [`string_units`]
+/// rather than re-deriving the `graphemes` vs `chars` split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LengthSemantics {
+    /// Count by grapheme cluster (the default, current behavior).
+    Grapheme,
+    /// Count by Unicode code point, matching PostgreSQL's `rpad`.
+    CodePoint,
+}
+
+/// Splits `string` into the units counted by `semantics`, preserving the
+/// UTF-8 boundaries needed to slice and re-concatenate safely.
+pub(crate) fn string_units(string: &str, semantics: LengthSemantics) -> Vec<&str> {
+    match semantics {
+        LengthSemantics::Grapheme => string.graphemes(true).collect(),
+        LengthSemantics::CodePoint => string
+            .char_indices()
+            .map(|(i, c)| &string[i..i + c.len_utf8()])
+            .collect(),
+    }
+}
+
+/// Fast path for the dominant `rpad(col, 20, ' ')` usage pattern, where
+/// `length` (and `fill`, if given) are literals and only the string argument
+/// varies by row. Bypasses `make_scalar_function`, which would otherwise
+/// materialize a length array and re-parse an identical fill string on every
+/// row; instead the numeric length and fill `Vec<char>` are computed once and
+/// only the string array is iterated. Returns `Ok(None)` when the arguments
+/// don't match this shape (non-literal length/fill, or a literal null),
+/// leaving the general array-oriented path to handle it.
+fn rpad_scalar_fast_path(args: &[ColumnarValue]) -> Result<Option<ColumnarValue>> {
+    let ColumnarValue::Array(string_array) = &args[0] else {
+        return Ok(None);
+    };
+    let ColumnarValue::Scalar(length_scalar) = &args[1] else {
+        return Ok(None);
+    };
+    let length = match length_scalar {
+        ScalarValue::Int64(Some(length)) => *length,
+        _ => return Ok(None),
+    };
+    if length > i32::MAX as i64 {
+        return exec_err!("rpad requested length {} too large", length);
+    }
+    let length = if length < 0 { 0 } else { length as usize };
+
+    let fill_chars: Vec<char> = match args.get(2) {
+        None => vec![' '],
+        Some(ColumnarValue::Scalar(fill_scalar)) => match fill_scalar {
+            ScalarValue::Utf8(Some(fill))
+            | ScalarValue::LargeUtf8(Some(fill))
+            | ScalarValue::Utf8View(Some(fill)) => fill.chars().collect(),
+            _ => return Ok(None),
+        },
+        Some(ColumnarValue::Array(_)) => return Ok(None),
+    };
+
+    let result: ArrayRef = match string_array.data_type() {
+        DataType::Utf8 => Arc::new(rpad_scalar_length_fill::<i32>(
+            as_generic_string_array::<i32>(string_array)?.iter(),
+            length,
+            &fill_chars,
+        )?),
+        DataType::LargeUtf8 => Arc::new(rpad_scalar_length_fill::<i64>(
+            as_generic_string_array::<i64>(string_array)?.iter(),
+            length,
+            &fill_chars,
+        )?),
+        DataType::Utf8View => Arc::new(rpad_scalar_length_fill::<i32>(
+            as_string_view_array(string_array)?.iter(),
+            length,
+            &fill_chars,
+        )?),
+        other => return exec_err!("Unsupported data type {other:?} for function rpad"),
+    };
+    Ok(Some(ColumnarValue::Array(result)))
+}
+
+/// Shared loop behind [`rpad_scalar_fast_path`]: pads or truncates every row
+/// of `strings` to the precomputed `length` (in grapheme clusters, matching
+/// [`rpad`]'s default semantics) using the precomputed `fill_chars`.
+fn rpad_scalar_length_fill<'a, O: OffsetSizeTrait>(
+    strings: impl Iterator<Item = Option<&'a str>>,
+    length: usize,
+    fill_chars: &[char],
+) -> Result<GenericStringArray<O>> {
+    strings
+        .map(|string| match string {
+            Some(string) => {
+                let units = string_units(string, LengthSemantics::Grapheme);
+                if length < units.len() {
+                    Ok(Some(units[..length].concat()))
+                } else if fill_chars.is_empty() {
+                    Ok(Some(string.to_string()))
+                } else {
+                    let mut s = string.to_string();
+                    let char_vector: Vec<char> = (0..length - units.len())
+                        .map(|l| fill_chars[l % fill_chars.len()])
+                        .collect();
+                    s.push_str(&char_vector.iter().collect::<String>());
+                    Ok(Some(s))
+                }
+            }
+            None => Ok(None),
+        })
+        .collect::<Result<GenericStringArray<O>>>()
+}
+
+macro_rules! process_rpad {
+    // For the two-argument case
+    ($string_array:expr, $length_array:expr, $semantics:expr) => {{
+        $string_array
+            .iter()
+            .zip($length_array.iter())
+            .map(|(string, length)| match (string, length) {
+                (Some(string), Some(length)) => {
+                    if length > i32::MAX as i64 {
+                        return exec_err!("rpad requested length {} too large", length);
+                    }
+
+                    let length = if length < 0 { 0 } else { length as usize };
+                    if length == 0 {
+                        Ok(Some("".to_string()))
+                    } else {
+                        let units = string_units(string, $semantics);
+                        if length < units.len() {
+                            Ok(Some(units[..length].concat()))
+                        } else {
+                            let mut s = string.to_string();
+                            s.push_str(" ".repeat(length - units.len()).as_str());
+                            Ok(Some(s))
+                        }
+                    }
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<GenericStringArray<StringArrayLen>>>()
+    }};
+
+    // For the three-argument case
+    ($string_array:expr, $length_array:expr, $fill_array:expr, $semantics:expr) => {{
+        $string_array
+            .iter()
+            .zip($length_array.iter())
+            .zip($fill_array.iter())
+            .map(|((string, length), fill)| match (string, length, fill) {
+                (Some(string), Some(length), Some(fill)) => {
+                    if length > i32::MAX as i64 {
+                        return exec_err!("rpad requested length {} too large", length);
+                    }
+
+                    let length = if length < 0 { 0 } else { length as usize };
+                    let units = string_units(string, $semantics);
+                    let fill_chars = fill.chars().collect::<Vec<char>>();
+
+                    if length < units.len() {
+                        Ok(Some(units[..length].concat()))
+                    } else if fill_chars.is_empty() {
+                        Ok(Some(string.to_string()))
+                    } else {
+                        let mut s = string.to_string();
+                        let char_vector: Vec<char> = (0..length - units.len())
+                            .map(|l| fill_chars[l % fill_chars.len()])
+                            .collect();
+                        s.push_str(&char_vector.iter().collect::<String>());
+                        Ok(Some(s))
+                    }
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<GenericStringArray<StringArrayLen>>>()
+    }};
+}
+
+fn rpad_impl<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
+    args: &[ArrayRef],
+    semantics: LengthSemantics,
+) -> Result<ArrayRef> {
+    match (args.len(), args[0].data_type()) {
+        (2, DataType::Utf8View) => {
+            let string_array = as_string_view_array(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+
+            let result = process_rpad!(string_array, length_array, semantics)?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
+        (2, _) => {
+            let string_array = as_generic_string_array::<StringArrayLen>(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+
+            let result = process_rpad!(string_array, length_array, semantics)?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
+        (3, DataType::Utf8View) => {
+            let string_array = as_string_view_array(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+            match args[2].data_type() {
+                DataType::Utf8View => {
+                    let fill_array = as_string_view_array(&args[2])?;
+                    let result =
+                        process_rpad!(string_array, length_array, fill_array, semantics)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
+                    let result =
+                        process_rpad!(string_array, length_array, fill_array, semantics)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                other_type => {
+                    exec_err!("unsupported type for rpad's third operator: {}", other_type)
+                }
+            }
+        }
+        (3, _) => {
+            let string_array = as_generic_string_array::<StringArrayLen>(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+            match args[2].data_type() {
+                DataType::Utf8View => {
+                    let fill_array = as_string_view_array(&args[2])?;
+                    let result =
+                        process_rpad!(string_array, length_array, fill_array, semantics)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
+                    let result =
+                        process_rpad!(string_array, length_array, fill_array, semantics)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                other_type => {
+                    exec_err!("unsupported type for rpad's third operator: {}", other_type)
+                }
+            }
+        }
+        (other, other_type) => exec_err!(
+            "rpad requires 2 or 3 arguments with corresponding types, but got {}. number of arguments with {}",
+            other, other_type
+        ),
+    }
+}
+
+/// Extends the string to length 'length' by appending the characters fill (a space by default). If the string is already longer than length then it is truncated.
+/// Counts `length` in grapheme clusters.
+/// rpad('hi', 5, 'xy') = 'hixyx'
+pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
+    args: &[ArrayRef],
+) -> Result<ArrayRef> {
+    rpad_impl::<StringArrayLen, FillArrayLen>(args, LengthSemantics::Grapheme)
+}
+
+/// Like [`rpad`], but counts `length` in Unicode code points rather than
+/// grapheme clusters, matching PostgreSQL's `rpad` semantics.
+/// rpad_codepoints('é', 1) truncates after the base `e`, separately from its
+/// combining accent, where `rpad` would keep the accented `é` as one unit.
+pub fn rpad_codepoints<
+    StringArrayLen: OffsetSizeTrait,
+    FillArrayLen: OffsetSizeTrait,
+>(
+    args: &[ArrayRef],
+) -> Result<ArrayRef> {
+    rpad_impl::<StringArrayLen, FillArrayLen>(args, LengthSemantics::CodePoint)
+}
+
+#[derive(Debug)]
+pub struct RPadCodepointsFunc {
+    signature: Signature,
+}
+
+impl Default for RPadCodepointsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RPadCodepointsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: rpad_like_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RPadCodepointsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "rpad_codepoints"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "rpad_codepoints")
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        match args.len() {
+            2 => match args[0].data_type() {
+                DataType::Utf8 | DataType::Utf8View => {
+                    make_scalar_function(rpad_codepoints::<i32, i32>, vec![])(args)
+                }
+                DataType::LargeUtf8 => {
+                    make_scalar_function(rpad_codepoints::<i64, i64>, vec![])(args)
+                }
+                other => exec_err!(
+                    "Unsupported data type {other:?} for function rpad_codepoints"
+                ),
+            },
+            3 => match (args[0].data_type(), args[2].data_type()) {
+                (
+                    DataType::Utf8 | DataType::Utf8View,
+                    DataType::Utf8 | DataType::Utf8View,
+                ) => make_scalar_function(rpad_codepoints::<i32, i32>, vec![])(args),
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad_codepoints::<i64, i64>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::Utf8View | DataType::Utf8) => {
+                    make_scalar_function(rpad_codepoints::<i64, i32>, vec![])(args)
+                }
+                (DataType::Utf8View | DataType::Utf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad_codepoints::<i32, i64>, vec![])(args)
+                }
+                (first_type, last_type) => {
+                    exec_err!("unsupported arguments type for rpad_codepoints, first argument type is {}, last argument type is {}", first_type, last_type)
+                }
+            },
+            number => {
+                exec_err!("unsupported arguments number {} for rpad_codepoints", number)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RPadDisplayFunc {
+    signature: Signature,
+}
+
+impl Default for RPadDisplayFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RPadDisplayFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: rpad_like_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RPadDisplayFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "rpad_display"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        utf8_to_str_type(&arg_types[0], "rpad_display")
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        match args.len() {
+            2 => match args[0].data_type() {
+                DataType::Utf8 | DataType::Utf8View => {
+                    make_scalar_function(rpad_display::<i32, i32>, vec![])(args)
+                }
+                DataType::LargeUtf8 => {
+                    make_scalar_function(rpad_display::<i64, i64>, vec![])(args)
+                }
+                other => {
+                    exec_err!("Unsupported data type {other:?} for function rpad_display")
+                }
+            },
+            3 => match (args[0].data_type(), args[2].data_type()) {
+                (
+                    DataType::Utf8 | DataType::Utf8View,
+                    DataType::Utf8 | DataType::Utf8View,
+                ) => make_scalar_function(rpad_display::<i32, i32>, vec![])(args),
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad_display::<i64, i64>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::Utf8View | DataType::Utf8) => {
+                    make_scalar_function(rpad_display::<i64, i32>, vec![])(args)
+                }
+                (DataType::Utf8View | DataType::Utf8, DataType::LargeUtf8) => {
+                    make_scalar_function(rpad_display::<i32, i64>, vec![])(args)
+                }
+                (first_type, last_type) => {
+                    exec_err!("unsupported arguments type for rpad_display, first argument type is {}, last argument type is {}", first_type, last_type)
+                }
+            },
+            number => {
+                exec_err!("unsupported arguments number {} for rpad_display", number)
+            }
+        }
+    }
+}
+
+/// Truncates `string` so its accumulated display (column) width does not
+/// exceed `width`. A grapheme cluster that would straddle the boundary is
+/// dropped rather than split, so the result may end up one column short of
+/// `width` instead of cutting a wide character in half.
+fn truncate_to_display_width(string: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut used = 0usize;
+    for grapheme in string.graphemes(true) {
+        let grapheme_width = UnicodeWidthStr::width(grapheme);
+        if used + grapheme_width > width {
+            break;
+        }
+        result.push_str(grapheme);
+        used += grapheme_width;
+    }
+    result
+}
+
+/// Pads `string` with repetitions of `fill` until it reaches `width` display
+/// columns, truncating `string` first via [`truncate_to_display_width`] if it
+/// is already wider than `width`; a truncated string is returned without
+/// padding. Padding stops at the first fill character that would push the
+/// total past `width`, so the result never overshoots the target width.
+fn pad_to_display_width(string: &str, width: usize, fill: &[char]) -> String {
+    let mut result = truncate_to_display_width(string, width);
+    let mut used = UnicodeWidthStr::width(result.as_str());
+    if fill.is_empty() || result.len() < string.len() {
+        return result;
+    }
+    let mut i = 0;
+    while used < width {
+        let fill_char = fill[i % fill.len()];
+        let fill_width = fill_char.width().unwrap_or(0);
+        // A zero-width fill char (e.g. a control character, where `width()`
+        // returns `None`, or a combining mark) never advances `used`, so
+        // looping on it would never terminate. Stop instead of spinning.
+        if fill_width == 0 {
+            break;
+        }
+        if used + fill_width > width {
+            break;
+        }
+        result.push(fill_char);
+        used += fill_width;
+        i += 1;
+    }
+    result
+}
+
+macro_rules! process_rpad_display {
+    // For the two-argument case
+    ($string_array:expr, $length_array:expr) => {{
+        $string_array
+            .iter()
+            .zip($length_array.iter())
+            .map(|(string, length)| match (string, length) {
+                (Some(string), Some(length)) => {
+                    if length > i32::MAX as i64 {
+                        return exec_err!(
+                            "rpad_display requested length {} too large",
+                            length
+                        );
+                    }
+
+                    let length = if length < 0 { 0 } else { length as usize };
+                    Ok(Some(pad_to_display_width(string, length, &[' '])))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<GenericStringArray<StringArrayLen>>>()
+    }};
+
+    // For the three-argument case
+    ($string_array:expr, $length_array:expr, $fill_array:expr) => {{
+        $string_array
+            .iter()
+            .zip($length_array.iter())
+            .zip($fill_array.iter())
+            .map(|((string, length), fill)| match (string, length, fill) {
+                (Some(string), Some(length), Some(fill)) => {
+                    if length > i32::MAX as i64 {
+                        return exec_err!(
+                            "rpad_display requested length {} too large",
+                            length
+                        );
+                    }
+
+                    let length = if length < 0 { 0 } else { length as usize };
+                    let fill_chars = fill.chars().collect::<Vec<char>>();
+                    Ok(Some(pad_to_display_width(string, length, &fill_chars)))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<GenericStringArray<StringArrayLen>>>()
+    }};
+}
+
+/// Extends `string` to `length` *display columns* (as opposed to [`rpad`], which
+/// counts grapheme clusters) by appending the characters of `fill` (a space by
+/// default), using the `unicode-width` crate to measure how many columns each
+/// grapheme cluster occupies. This keeps fixed-width output visually aligned
+/// even when it contains full-width CJK characters or wide emoji, at the cost
+/// of the result's character count no longer matching `length` exactly.
+/// rpad_display('日本', 5) = '日本 '
+pub fn rpad_display<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
+    args: &[ArrayRef],
+) -> Result<ArrayRef> {
+    match (args.len(), args[0].data_type()) {
+        (2, DataType::Utf8View) => {
+            let string_array = as_string_view_array(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+
+            let result = process_rpad_display!(string_array, length_array)?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
+        (2, _) => {
+            let string_array = as_generic_string_array::<StringArrayLen>(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+
+            let result = process_rpad_display!(string_array, length_array)?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
+        (3, DataType::Utf8View) => {
+            let string_array = as_string_view_array(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+            match args[2].data_type() {
+                DataType::Utf8View => {
+                    let fill_array = as_string_view_array(&args[2])?;
+                    let result =
+                        process_rpad_display!(string_array, length_array, fill_array)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
+                    let result =
+                        process_rpad_display!(string_array, length_array, fill_array)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                other_type => {
+                    exec_err!(
+                        "unsupported type for rpad_display's third operator: {}",
+                        other_type
+                    )
+                }
+            }
+        }
+        (3, _) => {
+            let string_array = as_generic_string_array::<StringArrayLen>(&args[0])?;
+            let length_array = as_int64_array(&args[1])?;
+            match args[2].data_type() {
+                DataType::Utf8View => {
+                    let fill_array = as_string_view_array(&args[2])?;
+                    let result =
+                        process_rpad_display!(string_array, length_array, fill_array)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
+                    let result =
+                        process_rpad_display!(string_array, length_array, fill_array)?;
+                    Ok(Arc::new(result) as ArrayRef)
+                }
+                other_type => {
+                    exec_err!(
+                        "unsupported type for rpad_display's third operator: {}",
+                        other_type
+                    )
+                }
+            }
+        }
+        (other, other_type) => exec_err!(
+            "rpad_display requires 2 or 3 arguments with corresponding types, but got {}. number of arguments with {}",
+            other, other_type
+        ),
+    }
+}
+
 #[derive(Debug)]
-pub struct RPadFunc {
+pub struct RPadBytesFunc {
     signature: Signature,
 }
 
-impl Default for RPadFunc {
+impl Default for RPadBytesFunc {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl RPadFunc {
+impl RPadBytesFunc {
     pub fn new() -> Self {
-        use DataType::*;
         Self {
-            signature: Signature::one_of(
-                vec![
-                    Exact(vec![Utf8View, Int64]),
-                    Exact(vec![Utf8View, Int64, Utf8View]),
-                    Exact(vec![Utf8View, Int64, Utf8]),
-                    Exact(vec![Utf8View, Int64, LargeUtf8]),
-                    Exact(vec![Utf8, Int64]),
-                    Exact(vec![Utf8, Int64, Utf8View]),
-                    Exact(vec![Utf8, Int64, Utf8]),
-                    Exact(vec![Utf8, Int64, LargeUtf8]),
-                    Exact(vec![LargeUtf8, Int64]),
-                    Exact(vec![LargeUtf8, Int64, Utf8View]),
-                    Exact(vec![LargeUtf8, Int64, Utf8]),
-                    Exact(vec![LargeUtf8, Int64, LargeUtf8]),
-                ],
-                Volatility::Immutable,
-            ),
+            signature: rpad_like_signature(),
         }
     }
 }
 
-impl ScalarUDFImpl for RPadFunc {
+impl ScalarUDFImpl for RPadBytesFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
 
     fn name(&self) -> &str {
-        "rpad"
+        "rpad_bytes"
     }
 
     fn signature(&self) -> &Signature {
@@ -80,46 +758,86 @@ impl ScalarUDFImpl for RPadFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "rpad")
+        utf8_to_str_type(&arg_types[0], "rpad_bytes")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
         match args.len() {
             2 => match args[0].data_type() {
                 DataType::Utf8 | DataType::Utf8View => {
-                    make_scalar_function(rpad::<i32, i32>, vec![])(args)
+                    make_scalar_function(rpad_bytes::<i32, i32>, vec![])(args)
                 }
                 DataType::LargeUtf8 => {
-                    make_scalar_function(rpad::<i64, i64>, vec![])(args)
+                    make_scalar_function(rpad_bytes::<i64, i64>, vec![])(args)
+                }
+                other => {
+                    exec_err!("Unsupported data type {other:?} for function rpad_bytes")
                 }
-                other => exec_err!("Unsupported data type {other:?} for function rpad"),
             },
             3 => match (args[0].data_type(), args[2].data_type()) {
                 (
                     DataType::Utf8 | DataType::Utf8View,
                     DataType::Utf8 | DataType::Utf8View,
-                ) => make_scalar_function(rpad::<i32, i32>, vec![])(args),
+                ) => make_scalar_function(rpad_bytes::<i32, i32>, vec![])(args),
                 (DataType::LargeUtf8, DataType::LargeUtf8) => {
-                    make_scalar_function(rpad::<i64, i64>, vec![])(args)
+                    make_scalar_function(rpad_bytes::<i64, i64>, vec![])(args)
                 }
                 (DataType::LargeUtf8, DataType::Utf8View | DataType::Utf8) => {
-                    make_scalar_function(rpad::<i64, i32>, vec![])(args)
+                    make_scalar_function(rpad_bytes::<i64, i32>, vec![])(args)
                 }
                 (DataType::Utf8View | DataType::Utf8, DataType::LargeUtf8) => {
-                    make_scalar_function(rpad::<i32, i64>, vec![])(args)
+                    make_scalar_function(rpad_bytes::<i32, i64>, vec![])(args)
                 }
                 (first_type, last_type) => {
-                    exec_err!("unsupported arguments type for rpad, first argument type is {}, last argument type is {}", first_type, last_type)
+                    exec_err!("unsupported arguments type for rpad_bytes, first argument type is {}, last argument type is {}", first_type, last_type)
                 }
             },
             number => {
-                exec_err!("unsupported arguments number {} for rpad", number)
+                exec_err!("unsupported arguments number {} for rpad_bytes", number)
             }
         }
     }
 }
 
-macro_rules! process_rpad {
+/// Truncates `string` to at most `budget` UTF-8 bytes. Never splits a
+/// multi-byte character: if `budget` lands mid-character, truncation backs up
+/// to the last whole-character boundary, so the result may be a few bytes
+/// short of `budget` rather than producing invalid UTF-8.
+fn truncate_to_byte_budget(string: &str, budget: usize) -> String {
+    if string.len() <= budget {
+        return string.to_string();
+    }
+    let mut end = budget;
+    while end > 0 && !string.is_char_boundary(end) {
+        end -= 1;
+    }
+    string[..end].to_string()
+}
+
+/// Extends `string` with repetitions of `fill` until it reaches `budget`
+/// UTF-8 bytes, truncating first via [`truncate_to_byte_budget`] if it is
+/// already over budget; a truncated string is returned without padding.
+/// Padding stops at the first fill character that would push the byte count
+/// past `budget`, so the result never exceeds `budget` bytes.
+fn pad_to_byte_budget(string: &str, budget: usize, fill: &[char]) -> String {
+    let mut result = truncate_to_byte_budget(string, budget);
+    if fill.is_empty() || result.len() < string.len() {
+        return result;
+    }
+    let mut i = 0;
+    while result.len() < budget {
+        let fill_char = fill[i % fill.len()];
+        let char_len = fill_char.len_utf8();
+        if result.len() + char_len > budget {
+            break;
+        }
+        result.push(fill_char);
+        i += 1;
+    }
+    result
+}
+
+macro_rules! process_rpad_bytes {
     // For the two-argument case
     ($string_array:expr, $length_array:expr) => {{
         $string_array
@@ -128,22 +846,14 @@ macro_rules! process_rpad {
             .map(|(string, length)| match (string, length) {
                 (Some(string), Some(length)) => {
                     if length > i32::MAX as i64 {
-                        return exec_err!("rpad requested length {} too large", length);
+                        return exec_err!(
+                            "rpad_bytes requested length {} too large",
+                            length
+                        );
                     }
 
                     let length = if length < 0 { 0 } else { length as usize };
-                    if length == 0 {
-                        Ok(Some("".to_string()))
-                    } else {
-                        let graphemes = string.graphemes(true).collect::<Vec<&str>>();
-                        if length < graphemes.len() {
-                            Ok(Some(graphemes[..length].concat()))
-                        } else {
-                            let mut s = string.to_string();
-                            s.push_str(" ".repeat(length - graphemes.len()).as_str());
-                            Ok(Some(s))
-                        }
-                    }
+                    Ok(Some(pad_to_byte_budget(string, length, &[' '])))
                 }
                 _ => Ok(None),
             })
@@ -159,25 +869,15 @@ macro_rules! process_rpad {
             .map(|((string, length), fill)| match (string, length, fill) {
                 (Some(string), Some(length), Some(fill)) => {
                     if length > i32::MAX as i64 {
-                        return exec_err!("rpad requested length {} too large", length);
+                        return exec_err!(
+                            "rpad_bytes requested length {} too large",
+                            length
+                        );
                     }
 
                     let length = if length < 0 { 0 } else { length as usize };
-                    let graphemes = string.graphemes(true).collect::<Vec<&str>>();
                     let fill_chars = fill.chars().collect::<Vec<char>>();
-
-                    if length < graphemes.len() {
-                        Ok(Some(graphemes[..length].concat()))
-                    } else if fill_chars.is_empty() {
-                        Ok(Some(string.to_string()))
-                    } else {
-                        let mut s = string.to_string();
-                        let char_vector: Vec<char> = (0..length - graphemes.len())
-                            .map(|l| fill_chars[l % fill_chars.len()])
-                            .collect();
-                        s.push_str(&char_vector.iter().collect::<String>());
-                        Ok(Some(s))
-                    }
+                    Ok(Some(pad_to_byte_budget(string, length, &fill_chars)))
                 }
                 _ => Ok(None),
             })
@@ -185,9 +885,13 @@ macro_rules! process_rpad {
     }};
 }
 
-/// Extends the string to length 'length' by appending the characters fill (a space by default). If the string is already longer than length then it is truncated.
-/// rpad('hi', 5, 'xy') = 'hixyx'
-pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
+/// Extends `string` to `length` *UTF-8 bytes* (as opposed to [`rpad`], which
+/// counts grapheme clusters, or [`rpad_display`], which counts display
+/// columns) by appending the characters of `fill` (a space by default). This
+/// is for downstream dialects and fixed-width file formats that need an exact
+/// byte budget, analogous to PostgreSQL's `octet_length` family.
+/// rpad_bytes('hi', 5) = 'hi   '
+pub fn rpad_bytes<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
     args: &[ArrayRef],
 ) -> Result<ArrayRef> {
     match (args.len(), args[0].data_type()) {
@@ -195,14 +899,14 @@ pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
             let string_array = as_string_view_array(&args[0])?;
             let length_array = as_int64_array(&args[1])?;
 
-            let result = process_rpad!(string_array, length_array)?;
+            let result = process_rpad_bytes!(string_array, length_array)?;
             Ok(Arc::new(result) as ArrayRef)
         }
         (2, _) => {
             let string_array = as_generic_string_array::<StringArrayLen>(&args[0])?;
             let length_array = as_int64_array(&args[1])?;
 
-            let result = process_rpad!(string_array, length_array)?;
+            let result = process_rpad_bytes!(string_array, length_array)?;
             Ok(Arc::new(result) as ArrayRef)
         }
         (3, DataType::Utf8View) => {
@@ -211,16 +915,21 @@ pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
             match args[2].data_type() {
                 DataType::Utf8View => {
                     let fill_array = as_string_view_array(&args[2])?;
-                    let result = process_rpad!(string_array, length_array, fill_array)?;
+                    let result =
+                        process_rpad_bytes!(string_array, length_array, fill_array)?;
                     Ok(Arc::new(result) as ArrayRef)
                 }
                 DataType::Utf8 | DataType::LargeUtf8 => {
                     let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
-                    let result = process_rpad!(string_array, length_array, fill_array)?;
+                    let result =
+                        process_rpad_bytes!(string_array, length_array, fill_array)?;
                     Ok(Arc::new(result) as ArrayRef)
                 }
                 other_type => {
-                    exec_err!("unsupported type for rpad's third operator: {}", other_type)
+                    exec_err!(
+                        "unsupported type for rpad_bytes's third operator: {}",
+                        other_type
+                    )
                 }
             }
         }
@@ -230,21 +939,26 @@ pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
             match args[2].data_type() {
                 DataType::Utf8View => {
                     let fill_array = as_string_view_array(&args[2])?;
-                    let result = process_rpad!(string_array, length_array, fill_array)?;
+                    let result =
+                        process_rpad_bytes!(string_array, length_array, fill_array)?;
                     Ok(Arc::new(result) as ArrayRef)
                 }
                 DataType::Utf8 | DataType::LargeUtf8 => {
                     let fill_array = as_generic_string_array::<FillArrayLen>(&args[2])?;
-                    let result = process_rpad!(string_array, length_array, fill_array)?;
+                    let result =
+                        process_rpad_bytes!(string_array, length_array, fill_array)?;
                     Ok(Arc::new(result) as ArrayRef)
                 }
                 other_type => {
-                    exec_err!("unsupported type for rpad's third operator: {}", other_type)
+                    exec_err!(
+                        "unsupported type for rpad_bytes's third operator: {}",
+                        other_type
+                    )
                 }
             }
         }
         (other, other_type) => exec_err!(
-            "rpad requires 2 or 3 arguments with corresponding types, but got {}. number of arguments with {}",
+            "rpad_bytes requires 2 or 3 arguments with corresponding types, but got {}. number of arguments with {}",
             other, other_type
         ),
     }
@@ -252,6 +966,8 @@ pub fn rpad<StringArrayLen: OffsetSizeTrait, FillArrayLen: OffsetSizeTrait>(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use arrow::array::{Array, StringArray};
     use arrow::datatypes::DataType::Utf8;
 
@@ -443,4 +1159,214 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rpad_scalar_fast_path_matches_array_path() -> Result<()> {
+        use arrow::array::Int64Array;
+
+        let strings = StringArray::from(vec![Some("hi"), None, Some("josé")]);
+        let length_scalar = ColumnarValue::Scalar(ScalarValue::from(5i64));
+        let fill_scalar = ColumnarValue::Scalar(ScalarValue::from("xy"));
+
+        // Fast path: length and fill are both scalars.
+        let fast = RPadFunc::new().invoke(&[
+            ColumnarValue::Array(Arc::new(strings.clone())),
+            length_scalar.clone(),
+            fill_scalar.clone(),
+        ])?;
+
+        // General path: force it by making `length` an array, which takes
+        // `rpad_scalar_fast_path`'s early return of `Ok(None)`.
+        let length_array = ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+            Some(5),
+            Some(5),
+            Some(5),
+        ])));
+        let general = RPadFunc::new().invoke(&[
+            ColumnarValue::Array(Arc::new(strings)),
+            length_array,
+            fill_scalar,
+        ])?;
+
+        let fast_array = fast.into_array(3)?;
+        let general_array = general.into_array(3)?;
+        assert_eq!(
+            fast_array.as_any().downcast_ref::<StringArray>().unwrap(),
+            general_array.as_any().downcast_ref::<StringArray>().unwrap(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rpad_codepoints_functions() -> Result<()> {
+        use crate::unicode::rpad::RPadCodepointsFunc;
+
+        // "é" here is `e` + a combining acute accent: two code points forming
+        // one grapheme cluster. `rpad` (grapheme semantics) keeps them
+        // together; `rpad_codepoints` truncates after the base `e`, matching
+        // PostgreSQL.
+        test_function!(
+            RPadCodepointsFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("e\u{0301}")),
+                ColumnarValue::Scalar(ScalarValue::from(1i64)),
+            ],
+            Ok(Some("e")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            RPadCodepointsFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("hi")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+                ColumnarValue::Scalar(ScalarValue::from("xy")),
+            ],
+            Ok(Some("hixyx")),
+            &str,
+            Utf8,
+            StringArray
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rpad_bytes_functions() -> Result<()> {
+        use crate::unicode::rpad::RPadBytesFunc;
+
+        test_function!(
+            RPadBytesFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("hi")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+            ],
+            Ok(Some("hi   ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // "é" is 2 bytes; padding "jos" (3 bytes) + "é" (2 bytes) = 5 bytes
+        // to a 6-byte budget adds a single trailing space.
+        test_function!(
+            RPadBytesFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("josé")),
+                ColumnarValue::Scalar(ScalarValue::from(6i64)),
+            ],
+            Ok(Some("josé ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // truncating mid-character backs up to the last whole-character
+        // boundary rather than producing invalid UTF-8, so the 5-byte
+        // "josé" truncated to a 4-byte budget drops the 2-byte "é"
+        // entirely and lands 1 byte short of the budget.
+        test_function!(
+            RPadBytesFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("josé")),
+                ColumnarValue::Scalar(ScalarValue::from(4i64)),
+            ],
+            Ok(Some("jos")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // a multi-byte fill character that would overshoot the budget stops
+        // the padding rather than exceeding `length`.
+        test_function!(
+            RPadBytesFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("ab")),
+                ColumnarValue::Scalar(ScalarValue::from(3i64)),
+                ColumnarValue::Scalar(ScalarValue::from("é")),
+            ],
+            Ok(Some("ab")),
+            &str,
+            Utf8,
+            StringArray
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rpad_display_functions() -> Result<()> {
+        use crate::unicode::rpad::RPadDisplayFunc;
+
+        // full-width characters occupy two display columns each, so "日本"
+        // (width 4) only needs one space to reach width 5, unlike grapheme-
+        // counting `rpad` which would add three.
+        test_function!(
+            RPadDisplayFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("日本")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+            ],
+            Ok(Some("日本 ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // truncating mid-wide-character stops before it rather than
+        // splitting the glyph, so the result is one column short of 5.
+        test_function!(
+            RPadDisplayFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("日本語")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+            ],
+            Ok(Some("日本")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // a wide fill character that would overshoot the target width stops
+        // the padding rather than exceeding `length`.
+        test_function!(
+            RPadDisplayFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("ab")),
+                ColumnarValue::Scalar(ScalarValue::from(3i64)),
+                ColumnarValue::Scalar(ScalarValue::from("日")),
+            ],
+            Ok(Some("ab")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            RPadDisplayFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("hi")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+                ColumnarValue::Scalar(ScalarValue::from("xy")),
+            ],
+            Ok(Some("hixyx")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // a zero-width fill char (a tab, which `UnicodeWidthChar::width`
+        // reports as `None`) can never advance the accumulated width; this
+        // must terminate rather than loop forever trying to reach `length`.
+        test_function!(
+            RPadDisplayFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("hi")),
+                ColumnarValue::Scalar(ScalarValue::from(5i64)),
+                ColumnarValue::Scalar(ScalarValue::from("\t")),
+            ],
+            Ok(Some("hi")),
+            &str,
+            Utf8,
+            StringArray
+        );
+
+        Ok(())
+    }
 }